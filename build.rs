@@ -2,6 +2,8 @@ use cfgrammar::yacc::{
     ast::{self, GrammarAST},
     YaccGrammar, YaccGrammarError, YaccGrammarWarning, YaccKind,
 };
+use cfgrammar::yacc::parser::SpansKind;
+use cfgrammar::NewlineCache;
 use cfgrammar::{PIdx, Span, Spanned};
 use lrlex::{CTLexerBuilder, LexBuildError, LexErrorHandler};
 use lrpar::{GrammarErrorHandler, LexerTypes};
@@ -9,6 +11,149 @@ use lrtable::{statetable::Conflicts, StateGraph, StateTable};
 use std::{cell::RefCell, error, fmt, path, rc::Rc, collections::HashSet};
 use ariadne::{Report, ReportKind, Label};
 
+/// A single resolved span within a [`JsonDiagnostic`], carrying both the raw
+/// byte offsets and their line/column resolution.
+#[derive(serde::Serialize)]
+struct JsonSpan {
+    start: usize,
+    end: usize,
+    line: usize,
+    column: usize,
+}
+
+/// A machine-applicable fix-it, mirroring rustc's JSON suggestion shape:
+/// `replacement` is the text to insert at `span`, and `applies_cleanly`
+/// tells tooling whether applying it is expected to resolve the
+/// diagnostic outright or is merely a starting point.
+#[derive(serde::Serialize)]
+struct JsonSuggestion {
+    message: String,
+    replacement: String,
+    span: JsonSpan,
+    applies_cleanly: bool,
+}
+
+/// A diagnostic in the shape consumed by editor/LSP tooling: one JSON object
+/// per line (newline-delimited), mirroring rustc's `--error-format=json`.
+#[derive(serde::Serialize)]
+struct JsonDiagnostic {
+    level: &'static str,
+    message: String,
+    path: String,
+    spans: Vec<JsonSpan>,
+    locale: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suggestion: Option<JsonSuggestion>,
+}
+
+fn json_spans(newline_cache: &NewlineCache, src: &str, spans: &[Span]) -> Vec<JsonSpan> {
+    spans
+        .iter()
+        .map(|span| {
+            let (line, column) = newline_cache
+                .byte_to_line_num_and_col_num(src, span.start())
+                .unwrap_or((0, 0));
+            JsonSpan {
+                start: span.start(),
+                end: span.end(),
+                line,
+                column,
+            }
+        })
+        .collect()
+}
+
+/// The default (English) message catalog, keyed by the stable diagnostic
+/// identifiers used throughout this file. `{$name}` placeholders are filled
+/// in from the named arguments passed to [`MessageCatalog::message`].
+const EN_MESSAGES: &[(&str, &str)] = &[
+    (
+        "lex-missing-in-lexer",
+        "The following tokens are used in the grammar but are not defined in the lexer:",
+    ),
+    (
+        "lex-missing-in-parser",
+        "The following tokens are used in the lexer but are not defined in the grammar",
+    ),
+    ("grammar-shift-reduce", "Shift/Reduce"),
+    ("grammar-reduce-reduce", "Reduce/Reduce"),
+    ("grammar-label-shifted", "Shifted"),
+    ("grammar-label-reduced-rule", "Reduced rule"),
+    ("grammar-label-reduced-production", "Reduced production"),
+    ("label-first-defined-here", "first defined here"),
+    ("label-also-defined-here", "also defined here"),
+    (
+        "grammar-label-fixit-sr-decl",
+        "fix-it: insert `{$decl}` here",
+    ),
+    (
+        "grammar-note-fixit-sr",
+        "fix-it: declare a precedence for '{$token}' (e.g. `{$decl}`) or add a `%prec` \
+         annotation to the reduction of '{$rule}'; a precedence declaration is expected to \
+         resolve this conflict",
+    ),
+    (
+        "grammar-label-fixit-rr",
+        "suggestion (not guaranteed to resolve): merge or reorder '{$this_rule}' relative to \
+         '{$other_rule}'",
+    ),
+    (
+        "grammar-note-fixit-rr",
+        "fix-it: merge the bodies of '{$rule1}' and '{$rule2}' into a single production, or \
+         reorder them so the intended one is listed first (reduce/reduce ties favour the \
+         earlier production); applying this is not guaranteed to resolve the conflict",
+    ),
+];
+
+/// A minimal Fluent-style message catalog: diagnostics are identified by a
+/// stable key (e.g. `grammar-shift-reduce`) rather than a hard-coded
+/// English string, and the human-readable text is resolved at emit time
+/// for a configurable locale. Falls back to the identifier itself if the
+/// locale has no entry for it, or if one of the template's `{$name}`
+/// placeholders wasn't supplied, so a bad translation never hides a
+/// diagnostic outright.
+struct MessageCatalog {
+    locale: String,
+    messages: std::collections::HashMap<&'static str, &'static str>,
+}
+
+impl MessageCatalog {
+    fn for_locale(locale: &str) -> Self {
+        // Only the default English catalog ships today; unknown locales
+        // fall back to it rather than emitting bare identifiers everywhere.
+        // `locale` reflects what was actually resolved, not merely the
+        // requested string, so callers can tell a fallback happened.
+        let (resolved_locale, messages) = match locale {
+            "en" => ("en", EN_MESSAGES.iter().copied().collect()),
+            _ => ("en", EN_MESSAGES.iter().copied().collect()),
+        };
+        Self {
+            locale: resolved_locale.to_owned(),
+            messages,
+        }
+    }
+
+    fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    fn message(&self, id: &str, args: &[(&str, &str)]) -> String {
+        let Some(template) = self.messages.get(id) else {
+            return id.to_owned();
+        };
+        let mut out = (*template).to_owned();
+        for (name, value) in args {
+            out = out.replace(&format!("{{${}}}", name), value);
+        }
+        if out.contains("{$") {
+            return id.to_owned();
+        }
+        out
+    }
+}
+
 const LEX_FILENAME: &str = "erroneous.l";
 const YACC_FILENAME: &str = "erroneous.y";
 
@@ -58,30 +203,77 @@ impl fmt::Debug for ErrorString {
     }
 }
 impl error::Error for ErrorString {}
+/// A report tagged with its sort key: the primary span's start offset and
+/// the report's message text, used to order diagnostics top-to-bottom
+/// through the file and to detect exact duplicates. `ariadne::Report` isn't
+/// `Ord`/`Eq`, so the key is tracked alongside it rather than derived from
+/// the finished report.
+type SortedReport<'a, S> = ((usize, String), Report<'a, S>);
+
+/// Order `reports` top-to-bottom through the file by sort key, dropping
+/// entries whose key exactly matches one already seen.
+fn ordered_reports<'a, 'b, S>(reports: &'b [SortedReport<'a, S>]) -> Vec<&'b Report<'a, S>> {
+    let mut idxs: Vec<usize> = (0..reports.len()).collect();
+    idxs.sort_by(|&a, &b| reports[a].0.cmp(&reports[b].0));
+    idxs.dedup_by(|&mut a, &mut b| reports[a].0 == reports[b].0);
+    idxs.into_iter().map(|i| &reports[i].1).collect()
+}
+
 struct AriadneLexErrorHandler<'a> {
     src: String,
     path: path::PathBuf,
-    reports: Vec<Report<'a, LSpan>>,
+    reports: Vec<SortedReport<'a, LSpan>>,
+    json_reports: Vec<JsonDiagnostic>,
+    newline_cache: NewlineCache,
+    catalog: Rc<MessageCatalog>,
 }
 
 struct AriadneGrammarErrorHandler<'a> {
     src: String,
     path: path::PathBuf,
-    err_reports: Vec<Report<'a, GSpan>>,
-    warning_reports: Vec<Report<'a, GSpan>>,
-    errors: String,
-    warnings: String,
+    err_reports: Vec<SortedReport<'a, GSpan>>,
+    warning_reports: Vec<SortedReport<'a, GSpan>>,
+    json_reports: Vec<JsonDiagnostic>,
     warnings_are_errors: bool,
-    //newline_cache: NewlineCache,
+    newline_cache: NewlineCache,
+    /// Graphviz DOT rendering of the LR state graph, populated once
+    /// `on_unexpected_conflicts` has run. Empty if the grammar had no
+    /// conflicts to report.
+    dot: String,
+    catalog: Rc<MessageCatalog>,
 }
 
 impl<'a> AriadneLexErrorHandler<'a> {
     fn new() -> Self {
+        Self::with_locale("en")
+    }
+
+    /// Like [`Self::new`], but selects the message catalog for `locale`.
+    /// Only an English catalog exists, so every locale resolves to it.
+    fn with_locale(locale: &str) -> Self {
         Self {
             src: String::new(),
             path: path::PathBuf::new(),
             reports: Vec::new(),
+            json_reports: Vec::new(),
+            newline_cache: NewlineCache::new(),
+            catalog: Rc::new(MessageCatalog::for_locale(locale)),
+        }
+    }
+
+    /// Serialize all diagnostics reported so far as newline-delimited JSON,
+    /// one object per diagnostic, for editor/LSP consumers.
+    fn results_json(&self) -> Result<String, Box<dyn error::Error>> {
+        let mut out = String::new();
+        for d in &self.json_reports {
+            out.push_str(&serde_json::to_string(d)?);
+            out.push('\n');
         }
+        Ok(out)
+    }
+
+    fn locale(&self) -> &str {
+        self.catalog.locale()
     }
 }
 
@@ -95,55 +287,102 @@ where
         self.path = path.to_owned();
     }
     fn lexer_src(&mut self, src: &str) {
-        self.src = src.to_owned()
+        self.src = src.to_owned();
+        self.newline_cache.feed(&self.src);
     }
 
     fn on_lex_build_error(&mut self, errs: &[LexBuildError]) {
         let path_name = self.path.display().to_string();
         for err in errs {
             let spans = err.spans();
-            let span = spans.first().unwrap();
-            let report = ariadne::Report::<LSpan>::build(
-                    ReportKind::Error, path_name.clone(), span.start(),
-                ).with_message(err.to_string());
-            self.reports.push(report.finish())
+            let Some(primary) = spans.first() else {
+                continue;
+            };
+            let message = err.to_string();
+            let mut report = ariadne::Report::<LSpan>::build(
+                    ReportKind::Error, path_name.clone(), primary.start(),
+                ).with_message(message.clone());
+            for (i, span) in spans.iter().enumerate() {
+                let label_msg = match (err.spanskind(), i) {
+                    (SpansKind::DuplicationError, 0) => {
+                        self.catalog.message("label-first-defined-here", &[])
+                    }
+                    (SpansKind::DuplicationError, _) => {
+                        self.catalog.message("label-also-defined-here", &[])
+                    }
+                    (SpansKind::Error, _) => err.to_string(),
+                };
+                report = report.with_label(
+                    Label::new(LSpan(path_name.clone(), *span)).with_message(label_msg),
+                );
+            }
+            self.reports.push(((primary.start(), message), report.finish()));
+            self.json_reports.push(JsonDiagnostic {
+                level: "error",
+                message: err.to_string(),
+                path: path_name.clone(),
+                spans: json_spans(&self.newline_cache, &self.src, spans),
+                locale: self.locale().to_owned(),
+                note: None,
+                suggestion: None,
+            });
         }
     }
 
     fn missing_in_lexer(&mut self, missing: &HashSet<String>) {
         let path_name = self.path.display().to_string();
+        let message = self.catalog.message("lex-missing-in-lexer", &[]);
         let mut report = Report::<LSpan>::build(
-            ReportKind::Error, path_name, 0,
-        ).with_message(
-            "The following tokens are used in the grammar but are not defined in the lexer:"
-        );
+            ReportKind::Error, path_name.clone(), 0,
+        ).with_message(message.clone());
+        let mut note = None;
         if !missing.is_empty() {
             let mut iter = missing.iter();
-            let mut note = String::from(iter.next().unwrap());
-            for n in missing {
-                note.push_str(format!(", {}", n).as_str());
+            let mut n = String::from(iter.next().unwrap());
+            for tok in iter {
+                n.push_str(format!(", {}", tok).as_str());
             }
-            report.set_note(note);
+            report.set_note(n.clone());
+            note = Some(n);
         }
-        self.reports.push(report.finish());
+        self.reports.push(((0, message.clone()), report.finish()));
+        self.json_reports.push(JsonDiagnostic {
+            level: "error",
+            message,
+            path: path_name,
+            spans: vec![],
+            locale: self.locale().to_owned(),
+            note,
+            suggestion: None,
+        });
     }
 
     fn missing_in_parser(&mut self, missing: &HashSet<String>) {
         let path_name = self.path.display().to_string();
+        let message = self.catalog.message("lex-missing-in-parser", &[]);
         let mut report = Report::<LSpan>::build(
-            ReportKind::Error, path_name, 0, // 0 not sure what else, EOF probably
-        ).with_message(
-            "The following tokens are used in the lexer but are not defined in the grammar"
-        );
+            ReportKind::Error, path_name.clone(), 0, // 0 not sure what else, EOF probably
+        ).with_message(message.clone());
+        let mut note = None;
         if !missing.is_empty() {
             let mut iter = missing.iter();
-            let mut note = String::from(iter.next().unwrap());
-            for n in missing {
-                note.push_str(format!(", {}", n).as_str());
+            let mut n = String::from(iter.next().unwrap());
+            for tok in iter {
+                n.push_str(format!(", {}", tok).as_str());
             }
-            report.set_note(note);
+            report.set_note(n.clone());
+            note = Some(n);
         }
-        self.reports.push(report.finish());
+        self.reports.push(((0, message.clone()), report.finish()));
+        self.json_reports.push(JsonDiagnostic {
+            level: "error",
+            message,
+            path: path_name,
+            spans: vec![],
+            locale: self.locale().to_owned(),
+            note,
+            suggestion: None,
+        });
     }
 
     fn results(&self) -> Result<(), Box<dyn error::Error>> {
@@ -155,7 +394,7 @@ where
             let mut srcs = ariadne::sources(vec![
                 (path_name, self.src.as_str()),
             ]);
-            for r in &self.reports {
+            for r in ordered_reports(&self.reports) {
                 r.write(&mut srcs, &mut x)?;
             }
             let s = String::from_utf8(x).unwrap();
@@ -166,15 +405,44 @@ where
 
 impl<'a> AriadneGrammarErrorHandler<'a> {
     fn new() -> Self {
+        Self::with_locale("en")
+    }
+
+    /// Like [`Self::new`], but selects the message catalog for `locale`.
+    /// Only an English catalog exists, so every locale resolves to it.
+    fn with_locale(locale: &str) -> Self {
         Self {
             src: String::new(),
             path: path::PathBuf::new(),
-            errors: String::new(),
-            warnings: String::new(),
             warnings_are_errors: false,
             err_reports: vec![],
             warning_reports: vec![],
+            json_reports: vec![],
+            newline_cache: NewlineCache::new(),
+            dot: String::new(),
+            catalog: Rc::new(MessageCatalog::for_locale(locale)),
+        }
+    }
+
+    /// Serialize all diagnostics reported so far as newline-delimited JSON,
+    /// one object per diagnostic, for editor/LSP consumers.
+    fn results_json(&self) -> Result<String, Box<dyn error::Error>> {
+        let mut out = String::new();
+        for d in &self.json_reports {
+            out.push_str(&serde_json::to_string(d)?);
+            out.push('\n');
         }
+        Ok(out)
+    }
+
+    /// The Graphviz DOT rendering of the LR state graph from the most
+    /// recent conflict report, if any.
+    fn dot(&self) -> &str {
+        &self.dot
+    }
+
+    fn locale(&self) -> &str {
+        self.catalog.locale()
     }
 }
 
@@ -189,6 +457,7 @@ where
 
     fn grammar_src(&mut self, src: &str) {
         self.src = src.to_owned();
+        self.newline_cache.feed(&self.src);
     }
     fn grammar_path(&mut self, path: &path::Path) {
         self.path = path.to_owned();
@@ -196,24 +465,75 @@ where
     fn on_grammar_warning(&mut self, warnings: &[YaccGrammarWarning]) {
         let path_name = self.path.display().to_string();
         for w in warnings {
-            // FIXME use this with label?
             let spans = w.spans();
-            let span = spans.first().unwrap();
-            let report = ariadne::Report::<GSpan>::build(
-                    ReportKind::Warning, path_name.clone(), span.start(),
-                ).with_message(w.to_string());
-            self.warning_reports.push(report.finish())
+            let Some(primary) = spans.first() else {
+                continue;
+            };
+            let message = w.to_string();
+            let mut report = ariadne::Report::<GSpan>::build(
+                    ReportKind::Warning, path_name.clone(), primary.start(),
+                ).with_message(message.clone());
+            for (i, span) in spans.iter().enumerate() {
+                let label_msg = match (w.spanskind(), i) {
+                    (SpansKind::DuplicationError, 0) => {
+                        self.catalog.message("label-first-defined-here", &[])
+                    }
+                    (SpansKind::DuplicationError, _) => {
+                        self.catalog.message("label-also-defined-here", &[])
+                    }
+                    (SpansKind::Error, _) => w.to_string(),
+                };
+                report = report.with_label(
+                    Label::new(GSpan(path_name.clone(), *span)).with_message(label_msg),
+                );
+            }
+            self.warning_reports.push(((primary.start(), message), report.finish()));
+            self.json_reports.push(JsonDiagnostic {
+                level: "warning",
+                message: w.to_string(),
+                path: path_name.clone(),
+                spans: json_spans(&self.newline_cache, &self.src, spans),
+                locale: self.locale().to_owned(),
+                note: None,
+                suggestion: None,
+            });
         }
     }
     fn on_grammar_error(&mut self, errs: &[YaccGrammarError]) {
         let path_name = self.path.display().to_string();
         for err in errs {
             let spans = err.spans();
-            let span = spans.first().unwrap();
-            let report = ariadne::Report::<GSpan>::build(
-                    ReportKind::Error, path_name.clone(), span.start(),
-                ).with_message(err.to_string());
-            self.err_reports.push(report.finish())
+            let Some(primary) = spans.first() else {
+                continue;
+            };
+            let message = err.to_string();
+            let mut report = ariadne::Report::<GSpan>::build(
+                    ReportKind::Error, path_name.clone(), primary.start(),
+                ).with_message(message.clone());
+            for (i, span) in spans.iter().enumerate() {
+                let label_msg = match (err.spanskind(), i) {
+                    (SpansKind::DuplicationError, 0) => {
+                        self.catalog.message("label-first-defined-here", &[])
+                    }
+                    (SpansKind::DuplicationError, _) => {
+                        self.catalog.message("label-also-defined-here", &[])
+                    }
+                    (SpansKind::Error, _) => err.to_string(),
+                };
+                report = report.with_label(
+                    Label::new(GSpan(path_name.clone(), *span)).with_message(label_msg),
+                );
+            }
+            self.err_reports.push(((primary.start(), message), report.finish()));
+            self.json_reports.push(JsonDiagnostic {
+                level: "error",
+                message: err.to_string(),
+                path: path_name.clone(),
+                spans: json_spans(&self.newline_cache, &self.src, spans),
+                locale: self.locale().to_owned(),
+                note: None,
+                suggestion: None,
+            });
         }
     }
 
@@ -221,7 +541,7 @@ where
         &mut self,
         ast: &GrammarAST,
         grm: &YaccGrammar<LexerTypesT::StorageT>,
-        _sgraph: &StateGraph<LexerTypesT::StorageT>,
+        sgraph: &StateGraph<LexerTypesT::StorageT>,
         _stable: &StateTable<LexerTypesT::StorageT>,
         c: &Conflicts<LexerTypesT::StorageT>,
     ) where
@@ -229,17 +549,20 @@ where
         LexerTypesT::StorageT:
             std::hash::Hash + 'static + num_traits::PrimInt + num_traits::Unsigned + fmt::Debug,
     {
-        let mut needs_newline = false;
         let path_name = self.path.display().to_string();
 
+        self.dot = state_graph_dot(ast, grm, sgraph, c);
+        let dot_path = self.path.with_extension("dot");
+        std::fs::write(&dot_path, &self.dot)
+            .unwrap_or_else(|e| panic!("couldn't write {}: {}", dot_path.display(), e));
+
         // I'm not sure yet what of this information is going to be helpful yet.
         // But here is i believe all of or a good amount of the span information related
         // to conflicts, their rules, productions the spans of those and their names.
         //
         // We'll need to figure out what we actually need
+        let header_span = header_decl_span(&self.src);
         for (r1_prod_idx, r2_prod_idx, _st_idx) in c.rr_conflicts() {
-            needs_newline = true;
-
             let (_r1_prod_names, _r1_prod_spans) = pidx_prods_data(ast, *r1_prod_idx);
             let (_r2_prod_names, _r2_prod_spans) = pidx_prods_data(ast, *r2_prod_idx);
 
@@ -247,52 +570,119 @@ where
             let r2_rule_idx = grm.prod_to_rule(*r2_prod_idx);
             let r1_span = grm.rule_name_span(r1_rule_idx);
             let r2_span = grm.rule_name_span(r2_rule_idx);
-            let _r1_name = grm.rule_name_str(r1_rule_idx);
-            let _r2_name = grm.rule_name_str(r2_rule_idx);
+            let r1_name = grm.rule_name_str(r1_rule_idx);
+            let r2_name = grm.rule_name_str(r2_rule_idx);
+            let reduce_reduce_msg = self.catalog.message("grammar-reduce-reduce", &[]);
             let report = ariadne::Report::<GSpan>::build(
                     ariadne::ReportKind::Error, path_name.clone(), r1_span.start(),
-                ).with_message("Reduce/Reduce".to_string())
-                .with_label(Label::new(GSpan(path_name.clone(), r1_span)).with_message("1st Reduce"))
-                .with_label(Label::new(GSpan(path_name.clone(), r2_span)).with_message("2nd Reduce"));
-            self.err_reports.push(report.finish());
-        }
-        if needs_newline {
-            self.errors.push('\n');
+                ).with_message(reduce_reduce_msg.clone())
+                .with_label(Label::new(GSpan(path_name.clone(), r1_span))
+                    .with_message(self.catalog.message(
+                        "grammar-label-fixit-rr",
+                        &[("this_rule", r1_name), ("other_rule", r2_name)],
+                    )))
+                .with_label(Label::new(GSpan(path_name.clone(), r2_span))
+                    .with_message(self.catalog.message(
+                        "grammar-label-fixit-rr",
+                        &[("this_rule", r2_name), ("other_rule", r1_name)],
+                    )))
+                .with_note(self.catalog.message(
+                    "grammar-note-fixit-rr",
+                    &[("rule1", r1_name), ("rule2", r2_name)],
+                ));
+            let rr_note = self.catalog.message(
+                "grammar-note-fixit-rr",
+                &[("rule1", r1_name), ("rule2", r2_name)],
+            );
+            self.json_reports.push(JsonDiagnostic {
+                level: "error",
+                message: reduce_reduce_msg.clone(),
+                path: path_name.clone(),
+                spans: json_spans(&self.newline_cache, &self.src, &[r1_span, r2_span]),
+                locale: self.locale().to_owned(),
+                note: Some(rr_note.clone()),
+                suggestion: Some(JsonSuggestion {
+                    message: rr_note,
+                    replacement: String::new(),
+                    span: json_spans(&self.newline_cache, &self.src, &[r1_span])
+                        .remove(0),
+                    // A merge/reorder is a restructuring suggestion, not a
+                    // guaranteed fix, so tooling shouldn't auto-apply it.
+                    applies_cleanly: false,
+                }),
+            });
+            self.err_reports.push(((r1_span.start(), reduce_reduce_msg), report.finish()));
         }
         for (s_tok_idx, r_prod_idx, _st_idx) in c.sr_conflicts() {
             let r_rule_idx = grm.prod_to_rule(*r_prod_idx);
             let s_tok_span = grm.token_span(*s_tok_idx).unwrap();
-            let _shift_name = grm.token_name(*s_tok_idx).unwrap();
-            let _reduce_name = grm.rule_name_str(r_rule_idx);
+            let shift_name = grm.token_name(*s_tok_idx).unwrap();
+            let reduce_name = grm.rule_name_str(r_rule_idx);
             let (_r_prod_names, r_prod_spans) = pidx_prods_data(ast, *r_prod_idx);
             let rule_idx = grm.prod_to_rule(*r_prod_idx);
             let rule_span = grm.rule_name_span(rule_idx);
+            let suggested_decl = format!("%left {}", shift_name);
+            let shift_reduce_msg = self.catalog.message("grammar-shift-reduce", &[]);
             let report = ariadne::Report::<GSpan>::build(
                     ariadne::ReportKind::Error, path_name.clone(), rule_span.start(),
-                ).with_message("Shift/Reduce".to_string())
-                .with_label(Label::new(GSpan(path_name.clone(), s_tok_span)).with_message("Shifted"))
-                .with_label(Label::new(GSpan(path_name.clone(), rule_span)).with_message("Reduced rule"));
+                ).with_message(shift_reduce_msg.clone())
+                .with_label(Label::new(GSpan(path_name.clone(), s_tok_span))
+                    .with_message(self.catalog.message("grammar-label-shifted", &[])))
+                .with_label(Label::new(GSpan(path_name.clone(), rule_span))
+                    .with_message(self.catalog.message("grammar-label-reduced-rule", &[])))
+                .with_label(Label::new(GSpan(path_name.clone(), header_span))
+                    .with_message(self.catalog.message(
+                        "grammar-label-fixit-sr-decl",
+                        &[("decl", &suggested_decl)],
+                    )));
             let report = r_prod_spans.iter().fold(report, |report, span| {
-                report.with_label(Label::new(GSpan(path_name.clone(), *span)).with_message("Reduced production"))
+                report.with_label(Label::new(GSpan(path_name.clone(), *span))
+                    .with_message(self.catalog.message("grammar-label-reduced-production", &[])))
+            });
+            let sr_note = self.catalog.message(
+                "grammar-note-fixit-sr",
+                &[
+                    ("token", shift_name),
+                    ("decl", &suggested_decl),
+                    ("rule", reduce_name),
+                ],
+            );
+            let report = report.with_note(sr_note.clone());
+            self.json_reports.push(JsonDiagnostic {
+                level: "error",
+                message: shift_reduce_msg.clone(),
+                path: path_name.clone(),
+                spans: json_spans(&self.newline_cache, &self.src, &[rule_span, s_tok_span]),
+                locale: self.locale().to_owned(),
+                note: Some(sr_note.clone()),
+                suggestion: Some(JsonSuggestion {
+                    message: sr_note,
+                    replacement: suggested_decl,
+                    span: json_spans(&self.newline_cache, &self.src, &[header_span])
+                        .remove(0),
+                    // A precedence declaration is expected to resolve this
+                    // conflict outright, per the catalog message above.
+                    applies_cleanly: true,
+                }),
             });
-            self.err_reports.push(report.finish());
+            self.err_reports.push(((rule_span.start(), shift_reduce_msg), report.finish()));
         }
     }
 
     fn results(&self) -> Result<(), Box<dyn error::Error>> {
-        if self.errors.is_empty() {
+        if self.err_reports.is_empty() {
             Ok(())
-        } else if self.warnings.is_empty() {
+        } else if self.warning_reports.is_empty() {
             let mut x: Vec<u8> = vec![];
             let path_name = self.path.display().to_string();
             let mut srcs = ariadne::sources(vec![
                 (path_name, self.src.as_str()),
             ]);
-            for r in &self.err_reports {
+            for r in ordered_reports(&self.err_reports) {
                 r.write(&mut srcs, &mut x)?;
             }
             let s = String::from_utf8(x).unwrap();
-            Err(ErrorString(format!("\n{}", s)).into()) 
+            Err(ErrorString(format!("\n{}", s)).into())
         } else {
             let mut srcs = ariadne::sources(vec![
                 (self.path.display().to_string(), self.src.as_str()),
@@ -301,13 +691,13 @@ where
             let (warnings, errors) =
                 ({
                     let mut x: Vec<u8> = vec![];
-                    for r in &self.warning_reports {
+                    for r in ordered_reports(&self.warning_reports) {
                         r.write(&mut srcs, &mut x)?;
                     }
                     String::from_utf8(x)?
                 }, {
                     let mut x: Vec<u8> = vec![];
-                    for r in &self.err_reports {
+                    for r in ordered_reports(&self.err_reports) {
                         r.write(&mut srcs, &mut x)?;
                     }
                     String::from_utf8(x)?
@@ -321,6 +711,94 @@ where
     }
 }
 
+/// The span in the `%%`-delimited header where a `%left`/`%right`/
+/// `%nonassoc` precedence declaration could be inserted: the start of the
+/// first line that begins with `%%`, which is the start of the source when
+/// the header is empty. Falls back to the start of the source if no line
+/// begins with `%%`.
+fn header_decl_span(src: &str) -> Span {
+    let pos = if src.starts_with("%%") {
+        0
+    } else {
+        src.find("\n%%").map(|p| p + 1).unwrap_or(0)
+    };
+    Span::new(pos, pos)
+}
+
+/// Render the LR state graph as a Graphviz DOT digraph, one node per
+/// `StIdx` labelled with its kernel items, edges labelled with the
+/// shifted/goto-ed symbol, and the states involved in `c`'s conflicts
+/// highlighted in red with a tooltip describing the conflict.
+fn state_graph_dot<StorageT>(
+    ast: &GrammarAST,
+    grm: &YaccGrammar<StorageT>,
+    sgraph: &StateGraph<StorageT>,
+    c: &Conflicts<StorageT>,
+) -> String
+where
+    usize: num_traits::AsPrimitive<StorageT>,
+    StorageT: std::hash::Hash + 'static + num_traits::PrimInt + num_traits::Unsigned + fmt::Debug,
+{
+    let mut conflict_tooltips: std::collections::HashMap<lrtable::StIdx<StorageT>, Vec<String>> =
+        std::collections::HashMap::new();
+    for (r1_prod_idx, r2_prod_idx, st_idx) in c.rr_conflicts() {
+        conflict_tooltips.entry(*st_idx).or_default().push(format!(
+            "reduce/reduce: '{}' vs '{}'",
+            grm.rule_name_str(grm.prod_to_rule(*r1_prod_idx)),
+            grm.rule_name_str(grm.prod_to_rule(*r2_prod_idx)),
+        ));
+    }
+    for (s_tok_idx, r_prod_idx, st_idx) in c.sr_conflicts() {
+        conflict_tooltips.entry(*st_idx).or_default().push(format!(
+            "shift/reduce: lookahead '{}' vs reduce '{}'",
+            grm.token_name(*s_tok_idx).unwrap_or("<anon>"),
+            grm.rule_name_str(grm.prod_to_rule(*r_prod_idx)),
+        ));
+    }
+
+    let mut dot = String::from("digraph lr_automaton {\n");
+    for stidx in sgraph.iter_stidxs() {
+        let idx = usize::from(stidx);
+        let kernel_items = sgraph
+            .core_state(stidx)
+            .items
+            .keys()
+            .map(|(pidx, dot_posn)| {
+                let ridx = grm.prod_to_rule(*pidx);
+                let (prod_syms, _) = pidx_prods_data(ast, *pidx);
+                let mut syms = prod_syms;
+                syms.insert(usize::from(*dot_posn).min(syms.len()), ".".to_string());
+                format!("{} ::= {}", grm.rule_name_str(ridx), syms.join(" "))
+            })
+            .collect::<Vec<_>>()
+            .join("\\l");
+        match conflict_tooltips.get(&stidx) {
+            Some(tooltips) => dot.push_str(&format!(
+                "  st{idx} [label=\"St{idx}\\l{kernel_items}\\l\", color=red, tooltip=\"{}\"];\n",
+                tooltips.join("; "),
+            )),
+            None => dot.push_str(&format!(
+                "  st{idx} [label=\"St{idx}\\l{kernel_items}\\l\"];\n",
+            )),
+        }
+        for (sym, dest_stidx) in sgraph.edges(stidx) {
+            let sym_name = match sym {
+                cfgrammar::Symbol::Token(tidx) => {
+                    grm.token_name(*tidx).unwrap_or("<anon>").to_string()
+                }
+                cfgrammar::Symbol::Rule(ridx) => grm.rule_name_str(*ridx).to_string(),
+            };
+            dot.push_str(&format!(
+                "  st{idx} -> st{} [label=\"{}\"];\n",
+                usize::from(*dest_stidx),
+                sym_name,
+            ));
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
+
 fn pidx_prods_data<StorageT>(ast: &GrammarAST, pidx: PIdx<StorageT>) -> (Vec<String>, Vec<Span>)
 where
     usize: num_traits::AsPrimitive<StorageT>,
@@ -356,7 +834,14 @@ fn main() -> Result<(), Box<dyn error::Error>> {
         .lexer_in_src_dir(LEX_FILENAME)
         .unwrap()
         .build()?;
-    eprintln!("warnings: {}", (*grammar_error_handler).borrow().warnings);
+    eprint!("{}", lex_error_handler.results_json()?);
+    eprint!("{}", (*grammar_error_handler).borrow().results_json()?);
+    if !(*grammar_error_handler).borrow().dot().is_empty() {
+        eprintln!(
+            "wrote LR automaton DOT graph (diagnostics locale: {})",
+            (*grammar_error_handler).borrow().locale(),
+        );
+    }
     // For debugging in case we succeed
     panic!();
 //    Ok(())